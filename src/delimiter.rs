@@ -0,0 +1,44 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delimiter {
+    Tab,
+    CR,
+    LF,
+    CRLF,
+    Custom(String),
+}
+
+impl Default for Delimiter {
+    fn default() -> Self {
+        Delimiter::Custom(",".to_string())
+    }
+}
+
+impl FromStr for Delimiter {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Tab" => Delimiter::Tab,
+            "CR" => Delimiter::CR,
+            "LF" => Delimiter::LF,
+            "CRLF" => Delimiter::CRLF,
+            other => Delimiter::Custom(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Delimiter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Delimiter::Tab => write!(f, "\t"),
+            Delimiter::CR => write!(f, "\r"),
+            Delimiter::LF => write!(f, "\n"),
+            Delimiter::CRLF => write!(f, "\r\n"),
+            Delimiter::Custom(s) => write!(f, "{}", s),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub struct NotFound(pub &'static str);
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` not found in Cargo.toml", self.0)
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+#[derive(Debug)]
+pub struct InheritanceError(pub &'static str);
+
+impl fmt::Display for InheritanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is inherited from the workspace, but no `[workspace.package]` table was found",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InheritanceError {}
+
+#[derive(Debug)]
+pub struct InvalidRustVersion(pub String);
+
+impl fmt::Display for InvalidRustVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid rust-version `{}`: expected MAJOR.MINOR[.PATCH] with no pre-release or build metadata",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidRustVersion {}
+
+#[derive(Debug)]
+pub struct InvalidSemver(pub semver::Error);
+
+impl fmt::Display for InvalidSemver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid semver: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidSemver {}
+
+#[derive(Debug)]
+pub struct MissingLockfile(pub PathBuf);
+
+impl fmt::Display for MissingLockfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no Cargo.lock found at {}", self.0.display())
+    }
+}
+
+impl std::error::Error for MissingLockfile {}
+
+#[derive(Debug)]
+pub struct LockedCrateNotFound(pub String);
+
+impl fmt::Display for LockedCrateNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` not found in Cargo.lock", self.0)
+    }
+}
+
+impl std::error::Error for LockedCrateNotFound {}
+
+#[derive(Debug)]
+pub struct AmbiguousLockedVersion {
+    pub name: String,
+    pub versions: Vec<String>,
+}
+
+impl fmt::Display for AmbiguousLockedVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "multiple locked versions of `{}` found: {}",
+            self.name,
+            self.versions.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for AmbiguousLockedVersion {}
@@ -0,0 +1,134 @@
+use cargo_toml::{Edition, Package};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::error::InheritanceError;
+
+pub fn edition_str(edition: &Edition) -> &'static str {
+    match edition {
+        Edition::E2015 => "2015",
+        Edition::E2018 => "2018",
+        Edition::E2021 => "2021",
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Json,
+    Toml,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "toml" => Ok(Format::Toml),
+            other => Err(format!(
+                "unknown format `{}`, expected `json` or `toml`",
+                other
+            )),
+        }
+    }
+}
+
+// Omits optional scalar fields absent from the manifest; `name`, `version`,
+// `edition` and the list fields are always emitted (lists as `[]` when absent).
+// Errors (like the bare-flag path in `output()`) when a present field's
+// workspace inheritance can't resolve.
+pub fn resolve_package(package: &Package) -> Result<Map<String, Value>, Box<dyn Error>> {
+    let mut fields = Map::new();
+
+    fields.insert("name".to_string(), json!(package.name));
+
+    let version = package
+        .version
+        .get()
+        .or(Err(InheritanceError("package.version")))?;
+    fields.insert("version".to_string(), json!(version));
+
+    let edition = package
+        .edition
+        .get()
+        .or(Err(InheritanceError("package.edition")))?;
+    fields.insert("edition".to_string(), json!(edition_str(edition)));
+
+    if let Some(description) = &package.description {
+        let description = description
+            .get()
+            .or(Err(InheritanceError("package.description")))?;
+        fields.insert("description".to_string(), json!(description));
+    }
+
+    if let Some(homepage) = &package.homepage {
+        let homepage = homepage
+            .get()
+            .or(Err(InheritanceError("package.homepage")))?;
+        fields.insert("homepage".to_string(), json!(homepage));
+    }
+
+    if let Some(license) = &package.license {
+        let license = license.get().or(Err(InheritanceError("package.license")))?;
+        fields.insert("license".to_string(), json!(license));
+    }
+
+    if let Some(rust_version) = &package.rust_version {
+        let rust_version = rust_version
+            .get()
+            .or(Err(InheritanceError("package.rust-version")))?;
+        fields.insert("rust-version".to_string(), json!(rust_version));
+    }
+
+    if let Some(links) = &package.links {
+        fields.insert("links".to_string(), json!(links));
+    }
+
+    let authors = package
+        .authors
+        .get()
+        .or(Err(InheritanceError("package.authors")))?;
+    fields.insert("authors".to_string(), json!(authors));
+
+    let keywords = package
+        .keywords
+        .get()
+        .or(Err(InheritanceError("package.keywords")))?;
+    fields.insert("keywords".to_string(), json!(keywords));
+
+    let categories = package
+        .categories
+        .get()
+        .or(Err(InheritanceError("package.categories")))?;
+    fields.insert("categories".to_string(), json!(categories));
+
+    Ok(fields)
+}
+
+// Render a resolved field map as the requested format, narrowing to a single
+// key first when the user selected one field.
+pub fn render(
+    format: Format,
+    fields: Map<String, Value>,
+    selected: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let value = match selected {
+        Some(key) => {
+            let mut single = Map::new();
+            if let Some(v) = fields.get(key) {
+                single.insert(key.to_string(), v.clone());
+            }
+            Value::Object(single)
+        }
+        None => Value::Object(fields),
+    };
+
+    match format {
+        Format::Json => Ok(serde_json::to_string(&value)?),
+        Format::Toml => {
+            let toml_value: toml::Value = serde_json::from_value(value)?;
+            Ok(toml::to_string_pretty(&toml_value)?)
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{AmbiguousLockedVersion, LockedCrateNotFound, MissingLockfile};
+
+#[derive(Debug, Deserialize)]
+pub struct Lockfile {
+    #[serde(rename = "package", default)]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl Lockfile {
+    pub fn read(lock_path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents =
+            fs::read_to_string(lock_path).map_err(|_| MissingLockfile(lock_path.to_path_buf()))?;
+
+        Ok(toml::from_str(&contents)?)
+    }
+
+    // Disambiguates by listing candidates when a name resolves to more than one version.
+    pub fn resolve(&self, name: &str) -> Result<&LockedPackage, Box<dyn Error>> {
+        let candidates: Vec<&LockedPackage> =
+            self.packages.iter().filter(|p| p.name == name).collect();
+
+        match candidates.as_slice() {
+            [] => Err(Box::new(LockedCrateNotFound(name.to_string()))),
+            [pkg] => Ok(pkg),
+            multiple => Err(Box::new(AmbiguousLockedVersion {
+                name: name.to_string(),
+                versions: multiple.iter().map(|p| p.version.clone()).collect(),
+            })),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod delimiter;
 mod error;
+mod format;
+mod lockfile;
 mod version;
 
 use cargo_toml::Manifest;
@@ -8,19 +10,29 @@ use delimiter::Delimiter;
 use error::InheritanceError;
 use error::InvalidSemver;
 use error::NotFound;
+use format::Format;
+use lockfile::Lockfile;
+use std::collections::HashSet;
 use std::env;
 use std::error::Error;
 use std::fs;
+use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::version::match_rust_version;
 use crate::version::match_version;
+use crate::version::parse_rust_version;
 
 fn main() -> Result<(), Box<dyn Error>> {
     let args = get_args();
-    let app = make_app();
+    let mut app = make_app();
 
-    let matches = app.get_matches_from(args);
+    let matches = app.clone().get_matches_from(args);
+
+    if let Some(completions) = matches.subcommand_matches("completions") {
+        return generate_completions(completions, &mut app);
+    }
 
     let entry_point = match matches.value_of("root") {
         Some(p) => p.parse()?,
@@ -34,7 +46,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let manifest = Manifest::from_path(&manifest_path)?;
 
-    if let Err(err) = output(&matches, manifest) {
+    if let Err(err) = output(&matches, manifest, &manifest_path) {
         eprintln!("Error: {}", err);
         std::process::exit(1);
     }
@@ -137,6 +149,14 @@ pub fn make_app() -> App<'static, 'static> {
                 .value_name("Tab | CR | LF | CRLF | String")
                 .global(true),
         )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .help("emit the resolved [package] table (or a single field) as structured output")
+                .value_name("json|toml")
+                .possible_values(&["json", "toml"])
+                .global(true),
+        )
         .group(ArgGroup::with_name("version-group").requires("version"))
         .group(ArgGroup::with_name("get").required(false).args(&[
             "authors",
@@ -180,6 +200,52 @@ pub fn make_app() -> App<'static, 'static> {
                         .help("get pre-release part"),
                 ),
         )
+        .subcommand(
+            App::new("package.dependencies")
+                .alias("deps")
+                .about("get package dependencies")
+                .arg(
+                    Arg::with_name("kind")
+                        .long("kind")
+                        .help("which dependency table to read")
+                        .value_name("normal|dev|build")
+                        .possible_values(&["normal", "dev", "build"])
+                        .default_value("normal"),
+                )
+                .arg(
+                    Arg::with_name("name")
+                        .long("name")
+                        .help("print a single dependency's version requirement")
+                        .value_name("DEP"),
+                ),
+        )
+        .subcommand(
+            App::new("completions")
+                .about("generate a shell completion script")
+                .arg(
+                    Arg::with_name("shell")
+                        .help("target shell")
+                        .value_name("bash|zsh|fish|powershell")
+                        .possible_values(&["bash", "zsh", "fish", "powershell"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("out-dir")
+                        .long("out-dir")
+                        .help("write the completion script here instead of stdout")
+                        .value_name("DIR"),
+                ),
+        )
+        .subcommand(
+            App::new("locked-version")
+                .about("get the exact version of a crate resolved in Cargo.lock")
+                .arg(
+                    Arg::with_name("crate")
+                        .help("crate name to resolve")
+                        .value_name("CRATE")
+                        .required(true),
+                ),
+        )
         .subcommand(App::new("package.authors").about("get package authors"))
         .subcommand(App::new("package.categories").about("get package categories"))
         .subcommand(App::new("package.description").about("get package description"))
@@ -187,8 +253,33 @@ pub fn make_app() -> App<'static, 'static> {
         .subcommand(App::new("package.homepage").about("get package homepage"))
         .subcommand(App::new("package.keywords").about("get package keywords"))
         .subcommand(App::new("package.license").about("get package license"))
+        .subcommand(
+            App::new("package.rust-version")
+                .setting(AppSettings::DisableVersion)
+                .setting(AppSettings::GlobalVersion)
+                .setting(AppSettings::DeriveDisplayOrder)
+                .setting(AppSettings::VersionlessSubcommands)
+                .about("get package.rust-version (MSRV)")
+                .arg(
+                    Arg::with_name("pretty")
+                        .long("pretty")
+                        .help("get pretty version eg. v1.70.0")
+                        .conflicts_with_all(&["major", "minor", "patch"]),
+                )
+                .arg(Arg::with_name("major").long("major").help("get major part"))
+                .arg(Arg::with_name("minor").long("minor").help("get minor part"))
+                .arg(Arg::with_name("patch").long("patch").help("get patch part")),
+        )
         .subcommand(App::new("package.version").about("get package version"))
-        .subcommand(App::new("workspace.members").about("get workspace members"))
+        .subcommand(
+            App::new("workspace.members")
+                .about("get workspace members")
+                .arg(
+                    Arg::with_name("paths")
+                        .long("paths")
+                        .help("print member paths instead of package names"),
+                ),
+        )
         .subcommand(App::new("workspace.package.authors").about("get workspace template authors"))
         .subcommand(
             App::new("workspace.package.categories").about("get workspace template categories"),
@@ -200,6 +291,23 @@ pub fn make_app() -> App<'static, 'static> {
         .subcommand(App::new("workspace.package.homepage").about("get workspace template homepage"))
         .subcommand(App::new("workspace.package.keywords").about("get workspace template keywords"))
         .subcommand(App::new("workspace.package.license").about("get workspace template license"))
+        .subcommand(
+            App::new("workspace.package.rust-version")
+                .setting(AppSettings::DisableVersion)
+                .setting(AppSettings::GlobalVersion)
+                .setting(AppSettings::DeriveDisplayOrder)
+                .setting(AppSettings::VersionlessSubcommands)
+                .about("get workspace template rust-version (MSRV)")
+                .arg(
+                    Arg::with_name("pretty")
+                        .long("pretty")
+                        .help("get pretty version eg. v1.70.0")
+                        .conflicts_with_all(&["major", "minor", "patch"]),
+                )
+                .arg(Arg::with_name("major").long("major").help("get major part"))
+                .arg(Arg::with_name("minor").long("minor").help("get minor part"))
+                .arg(Arg::with_name("patch").long("patch").help("get patch part")),
+        )
         .subcommand(
             App::new("workspace.package.version")
                 .setting(AppSettings::DisableVersion)
@@ -232,7 +340,11 @@ pub fn make_app() -> App<'static, 'static> {
         )
 }
 
-pub fn output(matches: &ArgMatches, manifest: Manifest) -> Result<(), Box<dyn Error>> {
+pub fn output(
+    matches: &ArgMatches,
+    manifest: Manifest,
+    manifest_path: &Path,
+) -> Result<(), Box<dyn Error>> {
     let package = || manifest.package.clone().ok_or(NotFound("package"));
     let workspace = || manifest.workspace.clone().ok_or(NotFound("workspace"));
     let ws_package = || workspace().and_then(|ws| ws.package.ok_or(NotFound("workspace.package")));
@@ -244,6 +356,53 @@ pub fn output(matches: &ArgMatches, manifest: Manifest) -> Result<(), Box<dyn Er
 
     let delim_string = delimiter.to_string();
 
+    if let Some(format) = matches.value_of("format") {
+        let format: Format = format.parse()?;
+
+        let unsupported_subcommand = [
+            "workspace.members",
+            "package.dependencies",
+            "locked-version",
+            "workspace.package.version",
+            "workspace.package.rust-version",
+        ]
+        .into_iter()
+        .find(|name| matches.subcommand_matches(name).is_some());
+
+        if let Some(name) = unsupported_subcommand {
+            return Err(format!("--format is not supported with the `{}` subcommand", name).into());
+        }
+
+        let fields = format::resolve_package(&package()?)?;
+
+        let selected = if matches.subcommand_matches("package.version").is_some() {
+            Some("version")
+        } else if matches.subcommand_matches("package.rust-version").is_some() {
+            if !fields.contains_key("rust-version") {
+                return Err(Box::new(NotFound("package.rust-version")));
+            }
+            Some("rust-version")
+        } else {
+            [
+                "name",
+                "homepage",
+                "license",
+                "description",
+                "links",
+                "authors",
+                "keywords",
+                "categories",
+                "edition",
+            ]
+            .into_iter()
+            .find(|flag| matches.is_present(flag))
+        };
+
+        println!("{}", format::render(format, fields, selected)?);
+
+        return Ok(());
+    }
+
     if let Some(version) = matches.subcommand_matches("package.version") {
         let v: semver::Version = package()?
             .version
@@ -255,6 +414,19 @@ pub fn output(matches: &ArgMatches, manifest: Manifest) -> Result<(), Box<dyn Er
         match_version(version, v, &delimiter)?;
     }
 
+    if let Some(rust_version) = matches.subcommand_matches("package.rust-version") {
+        let raw = package()?
+            .rust_version
+            .ok_or(NotFound("package.rust-version"))?
+            .get()
+            .or(Err(InheritanceError("package.rust-version")))?
+            .clone();
+
+        let v = parse_rust_version(&raw)?;
+
+        match_rust_version(rust_version, v, &delimiter)?;
+    }
+
     if matches.is_present("name") {
         println!("{}", package()?.name);
     } else if matches.is_present("homepage") {
@@ -314,16 +486,11 @@ pub fn output(matches: &ArgMatches, manifest: Manifest) -> Result<(), Box<dyn Er
                 .join(&delim_string)
         )
     } else if matches.is_present("edition") {
-        let edition = match package()?
+        let edition = package()?
             .edition
             .get()
-            .or(Err(InheritanceError("package.edition")))?
-        {
-            cargo_toml::Edition::E2015 => "2015",
-            cargo_toml::Edition::E2018 => "2018",
-            cargo_toml::Edition::E2021 => "2021",
-        };
-        println!("{}", edition);
+            .or(Err(InheritanceError("package.edition")))?;
+        println!("{}", format::edition_str(edition));
     } else if let Some(version) = matches.subcommand_matches("workspace.package.version") {
         let v: semver::Version = ws_package()?
             .version
@@ -332,11 +499,150 @@ pub fn output(matches: &ArgMatches, manifest: Manifest) -> Result<(), Box<dyn Er
             .map_err(InvalidSemver)?;
 
         match_version(version, v, &delimiter)?;
+    } else if let Some(rust_version) = matches.subcommand_matches("workspace.package.rust-version")
+    {
+        let raw = ws_package()?
+            .rust_version
+            .ok_or(NotFound("workspace.package.rust-version"))?;
+
+        let v = parse_rust_version(&raw)?;
+
+        match_rust_version(rust_version, v, &delimiter)?;
+    } else if let Some(lv) = matches.subcommand_matches("locked-version") {
+        let crate_name = lv.value_of("crate").expect("required arg");
+
+        let lock_path = manifest_path
+            .parent()
+            .ok_or(NotFound("workspace root"))?
+            .join("Cargo.lock");
+
+        let lockfile = Lockfile::read(&lock_path)?;
+        let locked = lockfile.resolve(crate_name)?;
+
+        println!("{}", locked.version);
+    } else if let Some(deps_matches) = matches.subcommand_matches("package.dependencies") {
+        let deps = match deps_matches.value_of("kind") {
+            Some("dev") => &manifest.dev_dependencies,
+            Some("build") => &manifest.build_dependencies,
+            _ => &manifest.dependencies,
+        };
+
+        if let Some(name) = deps_matches.value_of("name") {
+            let dep = deps
+                .get(name)
+                .ok_or_else(|| format!("dependency `{}` not found", name))?;
+
+            println!("{}", dependency_spec(dep));
+        } else {
+            let rendered: Vec<String> = deps
+                .iter()
+                .map(|(name, dep)| format!("{}={}", name, dependency_spec(dep)))
+                .collect();
+
+            println!("{}", rendered.join(&delim_string));
+        }
+    } else if let Some(wm) = matches.subcommand_matches("workspace.members") {
+        let members = resolve_workspace_members(&workspace()?, manifest_path)?;
+
+        let rendered: Result<Vec<String>, Box<dyn Error>> = members
+            .into_iter()
+            .map(|member| {
+                if wm.is_present("paths") {
+                    Ok(member.display().to_string())
+                } else {
+                    let name = Manifest::from_path(member.join("Cargo.toml"))?
+                        .package
+                        .ok_or(NotFound("package"))?
+                        .name;
+                    Ok(name)
+                }
+            })
+            .collect();
+
+        println!("{}", rendered?.join(&delim_string));
     }
 
     Ok(())
 }
 
+// Resolves `workspace.members` globs, minus `workspace.exclude`, to member directories.
+fn resolve_workspace_members(
+    workspace: &cargo_toml::Workspace,
+    manifest_path: &Path,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let root = manifest_path
+        .parent()
+        .ok_or(NotFound("workspace root"))?
+        .to_path_buf();
+
+    let exclude: Vec<PathBuf> = workspace
+        .exclude
+        .iter()
+        .map(|pattern| root.join(pattern))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut members = Vec::new();
+
+    for pattern in &workspace.members {
+        let full_pattern = root.join(pattern);
+        let full_pattern = full_pattern.to_str().ok_or("non UTF-8 member pattern")?;
+
+        for entry in glob::glob(full_pattern)? {
+            let path = entry?;
+
+            if !path.is_dir() {
+                continue;
+            }
+
+            let canonical = fs::canonicalize(&path)?;
+
+            if exclude
+                .iter()
+                .any(|excluded| matches_excluded(excluded, &canonical))
+            {
+                continue;
+            }
+
+            if !canonical.join("Cargo.toml").exists() {
+                continue;
+            }
+
+            if seen.insert(canonical.clone()) {
+                members.push(canonical);
+            }
+        }
+    }
+
+    Ok(members)
+}
+
+// A bare semver req for registry dependencies, a `git:`/`path:` prefix for git
+// and path sources, or `workspace` for dependencies inherited from the workspace.
+fn dependency_spec(dep: &cargo_toml::Dependency) -> String {
+    match dep {
+        cargo_toml::Dependency::Simple(version) => version.clone(),
+        cargo_toml::Dependency::Detailed(detail) => {
+            if let Some(git) = &detail.git {
+                format!("git:{}", git)
+            } else if let Some(path) = &detail.path {
+                format!("path:{}", path)
+            } else {
+                detail.version.clone().unwrap_or_default()
+            }
+        }
+        cargo_toml::Dependency::Inherited(_) => "workspace".to_string(),
+    }
+}
+
+// Exact-path equality only; unlike Cargo, a `workspace.exclude` pattern that is
+// itself a glob won't match anything here.
+fn matches_excluded(excluded: &Path, canonical: &Path) -> bool {
+    fs::canonicalize(excluded)
+        .map(|excluded| excluded == canonical)
+        .unwrap_or(false)
+}
+
 fn search_manifest_path(dir: &Path) -> Option<PathBuf> {
     let manifest = dir.join("Cargo.toml");
 
@@ -346,3 +652,22 @@ fn search_manifest_path(dir: &Path) -> Option<PathBuf> {
         dir.parent().and_then(search_manifest_path)
     }
 }
+
+fn generate_completions(matches: &ArgMatches, app: &mut App) -> Result<(), Box<dyn Error>> {
+    let shell = match matches.value_of("shell").expect("required arg") {
+        "bash" => clap::Shell::Bash,
+        "zsh" => clap::Shell::Zsh,
+        "fish" => clap::Shell::Fish,
+        "powershell" => clap::Shell::PowerShell,
+        other => return Err(format!("unsupported shell `{}`", other).into()),
+    };
+
+    let bin_name = app.get_name().to_string();
+
+    match matches.value_of("out-dir") {
+        Some(dir) => app.gen_completions(bin_name, shell, dir),
+        None => app.gen_completions_to(bin_name, shell, &mut io::stdout()),
+    }
+
+    Ok(())
+}
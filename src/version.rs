@@ -0,0 +1,85 @@
+use clap::ArgMatches;
+use std::error::Error;
+
+use crate::delimiter::Delimiter;
+use crate::error::InvalidRustVersion;
+
+// A `MAJOR.MINOR[.PATCH]` version with no pre-release or build metadata.
+#[derive(Debug, Clone, Copy)]
+pub struct PartialVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+pub fn parse_rust_version(s: &str) -> Result<PartialVersion, InvalidRustVersion> {
+    if s.contains('-') || s.contains('+') {
+        return Err(InvalidRustVersion(s.to_string()));
+    }
+
+    let mut parts = s.split('.');
+
+    let major = parts.next().and_then(|p| p.parse().ok());
+    let minor = parts.next().and_then(|p| p.parse().ok());
+    let patch = match parts.next() {
+        Some(p) => p.parse().ok(),
+        None => Some(0),
+    };
+
+    if parts.next().is_some() {
+        return Err(InvalidRustVersion(s.to_string()));
+    }
+
+    match (major, minor, patch) {
+        (Some(major), Some(minor), Some(patch)) => Ok(PartialVersion {
+            major,
+            minor,
+            patch,
+        }),
+        _ => Err(InvalidRustVersion(s.to_string())),
+    }
+}
+
+pub fn match_rust_version(
+    matches: &ArgMatches,
+    version: PartialVersion,
+    _delimiter: &Delimiter,
+) -> Result<(), Box<dyn Error>> {
+    if matches.is_present("major") {
+        println!("{}", version.major);
+    } else if matches.is_present("minor") {
+        println!("{}", version.minor);
+    } else if matches.is_present("patch") {
+        println!("{}", version.patch);
+    } else if matches.is_present("pretty") {
+        println!("v{}.{}.{}", version.major, version.minor, version.patch);
+    } else {
+        println!("{}.{}.{}", version.major, version.minor, version.patch);
+    }
+
+    Ok(())
+}
+
+pub fn match_version(
+    matches: &ArgMatches,
+    version: semver::Version,
+    _delimiter: &Delimiter,
+) -> Result<(), Box<dyn Error>> {
+    if matches.is_present("major") {
+        println!("{}", version.major);
+    } else if matches.is_present("minor") {
+        println!("{}", version.minor);
+    } else if matches.is_present("patch") {
+        println!("{}", version.patch);
+    } else if matches.is_present("build") {
+        println!("{}", version.build);
+    } else if matches.is_present("pre") {
+        println!("{}", version.pre);
+    } else if matches.is_present("pretty") {
+        println!("v{}", version);
+    } else {
+        println!("{}", version);
+    }
+
+    Ok(())
+}